@@ -0,0 +1,14 @@
+//! Linear algebra and geometry types for CICADA.
+//!
+//! Matrices are column-major and projections target Vulkan clip space
+//! (right-handed view space, depth in `0..=1`).
+
+mod mat;
+mod quat;
+mod rect;
+mod vec;
+
+pub use mat::{Mat3, Mat4};
+pub use quat::Quat;
+pub use rect::{Extent, Rect};
+pub use vec::{Vec2, Vec3, Vec4};
@@ -0,0 +1,475 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{Quat, Vec3, Vec4};
+
+/// A column-major 3x3 matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct Mat3 {
+    pub cols: [Vec3; 3],
+}
+
+/// A column-major 4x4 matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct Mat4 {
+    pub cols: [Vec4; 4],
+}
+
+impl Default for Mat3 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Default for Mat4 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Mat3 {
+    pub const ZERO: Self = Self::from_cols(Vec3::ZERO, Vec3::ZERO, Vec3::ZERO);
+    pub const IDENTITY: Self = Self::from_cols(Vec3::X, Vec3::Y, Vec3::Z);
+
+    pub const fn from_cols(x: Vec3, y: Vec3, z: Vec3) -> Self {
+        Self { cols: [x, y, z] }
+    }
+
+    pub fn from_scale(scale: Vec3) -> Self {
+        Self::from_cols(Vec3::X * scale.x, Vec3::Y * scale.y, Vec3::Z * scale.z)
+    }
+
+    pub fn from_quat(rotation: Quat) -> Self {
+        Self::from_cols(rotation * Vec3::X, rotation * Vec3::Y, rotation * Vec3::Z)
+    }
+
+    /// Returns the upper-left 3x3 part of `mat`.
+    pub fn from_mat4(mat: Mat4) -> Self {
+        Self::from_cols(
+            mat.cols[0].truncate(),
+            mat.cols[1].truncate(),
+            mat.cols[2].truncate(),
+        )
+    }
+
+    pub fn row(&self, index: usize) -> Vec3 {
+        Vec3::new(
+            self.cols[0][index],
+            self.cols[1][index],
+            self.cols[2][index],
+        )
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self::from_cols(self.row(0), self.row(1), self.row(2))
+    }
+
+    pub fn determinant(&self) -> f32 {
+        self.cols[2].dot(self.cols[0].cross(self.cols[1]))
+    }
+
+    /// Returns the inverse of the matrix, or `None` if it is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        let [x, y, z] = self.cols;
+        let det = self.determinant();
+        if det == 0.0 {
+            return None;
+        }
+
+        let adjugate = Self::from_cols(y.cross(z), z.cross(x), x.cross(y)).transpose();
+        Some(adjugate * (1.0 / det))
+    }
+}
+
+impl Mat4 {
+    pub const ZERO: Self = Self::from_cols(Vec4::ZERO, Vec4::ZERO, Vec4::ZERO, Vec4::ZERO);
+    pub const IDENTITY: Self = Self::from_cols(Vec4::X, Vec4::Y, Vec4::Z, Vec4::W);
+
+    pub const fn from_cols(x: Vec4, y: Vec4, z: Vec4, w: Vec4) -> Self {
+        Self { cols: [x, y, z, w] }
+    }
+
+    pub fn from_mat3(mat: Mat3) -> Self {
+        Self::from_cols(
+            mat.cols[0].extend(0.0),
+            mat.cols[1].extend(0.0),
+            mat.cols[2].extend(0.0),
+            Vec4::W,
+        )
+    }
+
+    pub fn from_translation(translation: Vec3) -> Self {
+        Self::from_cols(Vec4::X, Vec4::Y, Vec4::Z, translation.extend(1.0))
+    }
+
+    pub fn from_scale(scale: Vec3) -> Self {
+        Self::from_mat3(Mat3::from_scale(scale))
+    }
+
+    pub fn from_quat(rotation: Quat) -> Self {
+        Self::from_mat3(Mat3::from_quat(rotation))
+    }
+
+    /// Builds a transform that scales, then rotates, then translates.
+    pub fn from_scale_rotation_translation(scale: Vec3, rotation: Quat, translation: Vec3) -> Self {
+        let rotation = Mat3::from_quat(rotation);
+        Self::from_cols(
+            (rotation.cols[0] * scale.x).extend(0.0),
+            (rotation.cols[1] * scale.y).extend(0.0),
+            (rotation.cols[2] * scale.z).extend(0.0),
+            translation.extend(1.0),
+        )
+    }
+
+    /// Right-handed view matrix looking from `eye` towards `target`.
+    pub fn look_at_rh(eye: Vec3, target: Vec3, up: Vec3) -> Self {
+        Self::look_to_rh(eye, target - eye, up)
+    }
+
+    /// Right-handed view matrix looking from `eye` along `dir`.
+    pub fn look_to_rh(eye: Vec3, dir: Vec3, up: Vec3) -> Self {
+        let f = dir.normalize();
+        let s = f.cross(up).normalize();
+        let u = s.cross(f);
+
+        Self::from_cols(
+            Vec4::new(s.x, u.x, -f.x, 0.0),
+            Vec4::new(s.y, u.y, -f.y, 0.0),
+            Vec4::new(s.z, u.z, -f.z, 0.0),
+            Vec4::new(-s.dot(eye), -u.dot(eye), f.dot(eye), 1.0),
+        )
+    }
+
+    /// Right-handed perspective projection mapping depth `z_near..z_far` to `0..1`.
+    ///
+    /// `fov_y` is the vertical field of view in radians. Clip-space Y points
+    /// down, as Vulkan expects.
+    pub fn perspective_rh(fov_y: f32, aspect_ratio: f32, z_near: f32, z_far: f32) -> Self {
+        let f = 1.0 / (0.5 * fov_y).tan();
+        let range = z_far / (z_near - z_far);
+
+        Self::from_cols(
+            Vec4::new(f / aspect_ratio, 0.0, 0.0, 0.0),
+            Vec4::new(0.0, -f, 0.0, 0.0),
+            Vec4::new(0.0, 0.0, range, -1.0),
+            Vec4::new(0.0, 0.0, range * z_near, 0.0),
+        )
+    }
+
+    /// Right-handed perspective projection with an infinite far plane and reversed
+    /// depth, mapping `z_near` to 1 and infinity to 0.
+    pub fn perspective_infinite_reverse_rh(fov_y: f32, aspect_ratio: f32, z_near: f32) -> Self {
+        let f = 1.0 / (0.5 * fov_y).tan();
+
+        Self::from_cols(
+            Vec4::new(f / aspect_ratio, 0.0, 0.0, 0.0),
+            Vec4::new(0.0, -f, 0.0, 0.0),
+            Vec4::new(0.0, 0.0, 0.0, -1.0),
+            Vec4::new(0.0, 0.0, z_near, 0.0),
+        )
+    }
+
+    /// Right-handed orthographic projection mapping depth `z_near..z_far` to `0..1`.
+    ///
+    /// `top` maps to the top of the viewport, with clip-space Y pointing down.
+    pub fn orthographic_rh(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        z_near: f32,
+        z_far: f32,
+    ) -> Self {
+        let rcp_width = 1.0 / (right - left);
+        let rcp_height = 1.0 / (bottom - top);
+        let range = 1.0 / (z_near - z_far);
+
+        Self::from_cols(
+            Vec4::new(2.0 * rcp_width, 0.0, 0.0, 0.0),
+            Vec4::new(0.0, 2.0 * rcp_height, 0.0, 0.0),
+            Vec4::new(0.0, 0.0, range, 0.0),
+            Vec4::new(
+                -(right + left) * rcp_width,
+                -(bottom + top) * rcp_height,
+                range * z_near,
+                1.0,
+            ),
+        )
+    }
+
+    pub fn row(&self, index: usize) -> Vec4 {
+        Vec4::new(
+            self.cols[0][index],
+            self.cols[1][index],
+            self.cols[2][index],
+            self.cols[3][index],
+        )
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self::from_cols(self.row(0), self.row(1), self.row(2), self.row(3))
+    }
+
+    pub fn determinant(&self) -> f32 {
+        let [a, b, c, d] = self.cols;
+
+        let s0 = a.x * b.y - b.x * a.y;
+        let s1 = a.x * b.z - b.x * a.z;
+        let s2 = a.x * b.w - b.x * a.w;
+        let s3 = a.y * b.z - b.y * a.z;
+        let s4 = a.y * b.w - b.y * a.w;
+        let s5 = a.z * b.w - b.z * a.w;
+
+        let c5 = c.z * d.w - d.z * c.w;
+        let c4 = c.y * d.w - d.y * c.w;
+        let c3 = c.y * d.z - d.y * c.z;
+        let c2 = c.x * d.w - d.x * c.w;
+        let c1 = c.x * d.z - d.x * c.z;
+        let c0 = c.x * d.y - d.x * c.y;
+
+        s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0
+    }
+
+    /// Returns the inverse of the matrix, or `None` if it is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        let [a, b, c, d] = self.cols;
+
+        let s0 = a.x * b.y - b.x * a.y;
+        let s1 = a.x * b.z - b.x * a.z;
+        let s2 = a.x * b.w - b.x * a.w;
+        let s3 = a.y * b.z - b.y * a.z;
+        let s4 = a.y * b.w - b.y * a.w;
+        let s5 = a.z * b.w - b.z * a.w;
+
+        let c5 = c.z * d.w - d.z * c.w;
+        let c4 = c.y * d.w - d.y * c.w;
+        let c3 = c.y * d.z - d.y * c.z;
+        let c2 = c.x * d.w - d.x * c.w;
+        let c1 = c.x * d.z - d.x * c.z;
+        let c0 = c.x * d.y - d.x * c.y;
+
+        let det = s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0;
+        if det == 0.0 {
+            return None;
+        }
+
+        let inverse = Self::from_cols(
+            Vec4::new(
+                b.y * c5 - b.z * c4 + b.w * c3,
+                -a.y * c5 + a.z * c4 - a.w * c3,
+                d.y * s5 - d.z * s4 + d.w * s3,
+                -c.y * s5 + c.z * s4 - c.w * s3,
+            ),
+            Vec4::new(
+                -b.x * c5 + b.z * c2 - b.w * c1,
+                a.x * c5 - a.z * c2 + a.w * c1,
+                -d.x * s5 + d.z * s2 - d.w * s1,
+                c.x * s5 - c.z * s2 + c.w * s1,
+            ),
+            Vec4::new(
+                b.x * c4 - b.y * c2 + b.w * c0,
+                -a.x * c4 + a.y * c2 - a.w * c0,
+                d.x * s4 - d.y * s2 + d.w * s0,
+                -c.x * s4 + c.y * s2 - c.w * s0,
+            ),
+            Vec4::new(
+                -b.x * c3 + b.y * c1 - b.z * c0,
+                a.x * c3 - a.y * c1 + a.z * c0,
+                -d.x * s3 + d.y * s1 - d.z * s0,
+                c.x * s3 - c.y * s1 + c.z * s0,
+            ),
+        );
+        Some(inverse * (1.0 / det))
+    }
+
+    /// Transforms a point, applying translation.
+    pub fn transform_point3(&self, point: Vec3) -> Vec3 {
+        (*self * point.extend(1.0)).truncate()
+    }
+
+    /// Transforms a direction, ignoring translation.
+    pub fn transform_vector3(&self, vector: Vec3) -> Vec3 {
+        (*self * vector.extend(0.0)).truncate()
+    }
+
+    pub fn to_cols_array(&self) -> [f32; 16] {
+        let [x, y, z, w] = self.cols.map(Vec4::to_array);
+        [
+            x[0], x[1], x[2], x[3], y[0], y[1], y[2], y[3], z[0], z[1], z[2], z[3], w[0], w[1],
+            w[2], w[3],
+        ]
+    }
+}
+
+impl Mul<Vec3> for Mat3 {
+    type Output = Vec3;
+
+    fn mul(self, rhs: Vec3) -> Vec3 {
+        self.cols[0] * rhs.x + self.cols[1] * rhs.y + self.cols[2] * rhs.z
+    }
+}
+
+impl Mul for Mat3 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            cols: rhs.cols.map(|col| self * col),
+        }
+    }
+}
+
+impl Mul<f32> for Mat3 {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        Self {
+            cols: self.cols.map(|col| col * rhs),
+        }
+    }
+}
+
+impl MulAssign for Mat3 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Mul<Vec4> for Mat4 {
+    type Output = Vec4;
+
+    fn mul(self, rhs: Vec4) -> Vec4 {
+        self.cols[0] * rhs.x + self.cols[1] * rhs.y + self.cols[2] * rhs.z + self.cols[3] * rhs.w
+    }
+}
+
+impl Mul for Mat4 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            cols: rhs.cols.map(|col| self * col),
+        }
+    }
+}
+
+impl Mul<f32> for Mat4 {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        Self {
+            cols: self.cols.map(|col| col * rhs),
+        }
+    }
+}
+
+impl MulAssign for Mat4 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_mat4_eq(a: Mat4, b: Mat4) {
+        let close = a
+            .to_cols_array()
+            .iter()
+            .zip(b.to_cols_array())
+            .all(|(a, b)| (a - b).abs() < 1e-5);
+        assert!(close, "{a:?} != {b:?}");
+    }
+
+    fn assert_vec_eq(a: Vec3, b: Vec3) {
+        assert!((a - b).length() < 1e-5, "{a:?} != {b:?}");
+    }
+
+    fn transform() -> Mat4 {
+        Mat4::from_scale_rotation_translation(
+            Vec3::new(2.0, 3.0, 0.5),
+            Quat::from_axis_angle(Vec3::new(1.0, 2.0, 3.0).normalize(), 0.7),
+            Vec3::new(1.0, -2.0, 5.0),
+        )
+    }
+
+    #[test]
+    fn mat4_inverse_round_trips() {
+        let m = transform();
+        let inverse = m.inverse().unwrap();
+        assert_mat4_eq(m * inverse, Mat4::IDENTITY);
+        assert_mat4_eq(inverse * m, Mat4::IDENTITY);
+    }
+
+    #[test]
+    fn mat4_determinant_of_scale() {
+        let m = Mat4::from_scale(Vec3::new(2.0, 3.0, 4.0));
+        assert!((m.determinant() - 24.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn mat3_inverse_round_trips() {
+        let m = Mat3::from_mat4(transform());
+        let product = Mat4::from_mat3(m * m.inverse().unwrap());
+        assert_mat4_eq(product, Mat4::IDENTITY);
+    }
+
+    #[test]
+    fn singular_matrices_have_no_inverse() {
+        assert_eq!(Mat3::ZERO.inverse(), None);
+        assert_eq!(Mat4::ZERO.inverse(), None);
+        assert_eq!(Mat4::from_scale(Vec3::new(1.0, 0.0, 1.0)).inverse(), None);
+    }
+
+    #[test]
+    fn translation_moves_points_but_not_vectors() {
+        let m = Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(m.transform_point3(Vec3::ZERO), Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(m.transform_vector3(Vec3::X), Vec3::X);
+    }
+
+    #[test]
+    fn look_at_moves_target_onto_negative_z() {
+        let eye = Vec3::new(3.0, 4.0, 5.0);
+        let view = Mat4::look_at_rh(eye, Vec3::ZERO, Vec3::Y);
+        assert_vec_eq(view.transform_point3(eye), Vec3::ZERO);
+        assert_vec_eq(
+            view.transform_point3(Vec3::ZERO),
+            Vec3::new(0.0, 0.0, -eye.length()),
+        );
+        assert_mat4_eq(view * view.inverse().unwrap(), Mat4::IDENTITY);
+    }
+
+    #[test]
+    fn perspective_maps_near_and_far_to_unit_depth() {
+        let proj = Mat4::perspective_rh(1.0, 1.5, 0.1, 100.0);
+
+        let near = proj * Vec4::new(0.0, 1.0, -0.1, 1.0);
+        let far = proj * Vec4::new(0.0, 0.0, -100.0, 1.0);
+        assert!((near.z / near.w).abs() < 1e-6);
+        assert!((far.z / far.w - 1.0).abs() < 1e-6);
+        assert!(near.y < 0.0, "view-space up should map to clip-space -Y");
+    }
+
+    #[test]
+    fn infinite_reverse_perspective_maps_near_to_one() {
+        let proj = Mat4::perspective_infinite_reverse_rh(1.0, 1.5, 0.1);
+
+        let near = proj * Vec4::new(0.0, 0.0, -0.1, 1.0);
+        let far = proj * Vec4::new(0.0, 0.0, -1.0e6, 1.0);
+        assert!((near.z / near.w - 1.0).abs() < 1e-6);
+        assert!((far.z / far.w).abs() < 1e-6);
+    }
+
+    #[test]
+    fn orthographic_maps_top_left_to_negative_corner() {
+        let proj = Mat4::orthographic_rh(0.0, 800.0, 600.0, 0.0, 0.0, 1.0);
+
+        let top_left = proj * Vec4::new(0.0, 0.0, 0.0, 1.0);
+        let bottom_right = proj * Vec4::new(800.0, 600.0, -1.0, 1.0);
+        assert_eq!(top_left, Vec4::new(-1.0, -1.0, 0.0, 1.0));
+        assert_eq!(bottom_right, Vec4::new(1.0, 1.0, 1.0, 1.0));
+    }
+}
@@ -0,0 +1,197 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::Vec3;
+
+/// A rotation quaternion with the vector part in `x`, `y`, `z` and the scalar part in `w`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct Quat {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Default for Quat {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Quat {
+    pub const IDENTITY: Self = Self::from_xyzw(0.0, 0.0, 0.0, 1.0);
+
+    pub const fn from_xyzw(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Rotation of `angle` radians around `axis`, which must be normalized.
+    pub fn from_axis_angle(axis: Vec3, angle: f32) -> Self {
+        let (sin, cos) = (0.5 * angle).sin_cos();
+        let v = axis * sin;
+        Self::from_xyzw(v.x, v.y, v.z, cos)
+    }
+
+    pub fn from_rotation_x(angle: f32) -> Self {
+        Self::from_axis_angle(Vec3::X, angle)
+    }
+
+    pub fn from_rotation_y(angle: f32) -> Self {
+        Self::from_axis_angle(Vec3::Y, angle)
+    }
+
+    pub fn from_rotation_z(angle: f32) -> Self {
+        Self::from_axis_angle(Vec3::Z, angle)
+    }
+
+    pub const fn xyz(self) -> Vec3 {
+        Vec3::new(self.x, self.y, self.z)
+    }
+
+    pub fn dot(self, rhs: Self) -> f32 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+
+    pub fn length(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    /// Returns the quaternion scaled to unit length, or `IDENTITY` if its length is zero.
+    pub fn normalize(self) -> Self {
+        let length = self.length();
+        if length > 0.0 {
+            let rcp = 1.0 / length;
+            Self::from_xyzw(self.x * rcp, self.y * rcp, self.z * rcp, self.w * rcp)
+        } else {
+            Self::IDENTITY
+        }
+    }
+
+    pub fn conjugate(self) -> Self {
+        Self::from_xyzw(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Inverse of a normalized quaternion.
+    pub fn inverse(self) -> Self {
+        self.conjugate()
+    }
+
+    /// Spherical interpolation between two normalized quaternions along the shortest arc.
+    pub fn slerp(self, mut end: Self, t: f32) -> Self {
+        let mut cos = self.dot(end);
+        if cos < 0.0 {
+            end = Self::from_xyzw(-end.x, -end.y, -end.z, -end.w);
+            cos = -cos;
+        }
+
+        let (s0, s1) = if cos > 0.9995 {
+            // Nearly parallel; fall back to a normalized lerp to avoid dividing by ~0.
+            (1.0 - t, t)
+        } else {
+            let theta = cos.acos();
+            let rcp_sin = 1.0 / theta.sin();
+            (
+                ((1.0 - t) * theta).sin() * rcp_sin,
+                (t * theta).sin() * rcp_sin,
+            )
+        };
+
+        Self::from_xyzw(
+            self.x * s0 + end.x * s1,
+            self.y * s0 + end.y * s1,
+            self.z * s0 + end.z * s1,
+            self.w * s0 + end.w * s1,
+        )
+        .normalize()
+    }
+}
+
+impl Mul for Quat {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::from_xyzw(
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        )
+    }
+}
+
+impl MulAssign for Quat {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Mul<Vec3> for Quat {
+    type Output = Vec3;
+
+    fn mul(self, rhs: Vec3) -> Vec3 {
+        let u = self.xyz();
+        let t = u.cross(rhs) * 2.0;
+        rhs + t * self.w + u.cross(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    use super::*;
+    use crate::Mat3;
+
+    fn assert_vec_eq(a: Vec3, b: Vec3) {
+        assert!((a - b).length() < 1e-5, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn rotates_vectors() {
+        let q = Quat::from_rotation_z(FRAC_PI_2);
+        assert_vec_eq(q * Vec3::X, Vec3::Y);
+        assert_vec_eq(Mat3::from_quat(q) * Vec3::X, Vec3::Y);
+    }
+
+    #[test]
+    fn product_applies_right_operand_first() {
+        let a = Quat::from_rotation_x(0.3);
+        let b = Quat::from_rotation_y(1.1);
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert_vec_eq((a * b) * v, a * (b * v));
+        assert_vec_eq((a * a.inverse()) * v, v);
+    }
+
+    #[test]
+    fn normalize_zero_is_identity() {
+        assert_eq!(
+            Quat::from_xyzw(0.0, 0.0, 0.0, 0.0).normalize(),
+            Quat::IDENTITY
+        );
+    }
+
+    #[test]
+    fn slerp_endpoints_and_midpoint() {
+        let end = Quat::from_rotation_z(FRAC_PI_2);
+        assert_vec_eq(Quat::IDENTITY.slerp(end, 0.0) * Vec3::X, Vec3::X);
+        assert_vec_eq(Quat::IDENTITY.slerp(end, 1.0) * Vec3::X, Vec3::Y);
+        assert_vec_eq(
+            Quat::IDENTITY.slerp(end, 0.5) * Vec3::X,
+            Quat::from_rotation_z(FRAC_PI_4) * Vec3::X,
+        );
+    }
+
+    #[test]
+    fn slerp_takes_shortest_arc_for_antipodal_input() {
+        let start = Quat::from_rotation_z(0.2);
+        let end = Quat::from_rotation_z(0.6);
+        let flipped = Quat::from_xyzw(-end.x, -end.y, -end.z, -end.w);
+
+        let v = Vec3::X;
+        assert_vec_eq(start.slerp(flipped, 0.5) * v, start.slerp(end, 0.5) * v);
+        assert_vec_eq(
+            start.slerp(flipped, 0.5) * v,
+            Quat::from_rotation_z(0.4) * v,
+        );
+    }
+}
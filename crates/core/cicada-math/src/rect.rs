@@ -0,0 +1,180 @@
+/// A two-dimensional size in whole units, such as pixels.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct Extent {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Extent {
+    pub const ZERO: Self = Self::new(0, 0);
+
+    pub const fn new(width: u32, height: u32) -> Self {
+        Self { width, height }
+    }
+
+    pub const fn area(self) -> u64 {
+        self.width as u64 * self.height as u64
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Width divided by height, or `None` for an empty extent.
+    pub fn aspect_ratio(self) -> Option<f32> {
+        (!self.is_empty()).then(|| self.width as f32 / self.height as f32)
+    }
+}
+
+/// An axis-aligned rectangle given by its top-left corner and extent.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct Rect {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Rect {
+    pub const fn new(x: i32, y: i32, width: u32, height: u32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub const fn from_extent(x: i32, y: i32, extent: Extent) -> Self {
+        Self::new(x, y, extent.width, extent.height)
+    }
+
+    /// Builds a rectangle from its edges.
+    ///
+    /// Returns `None` if `right < left` or `bottom < top`, or if the top-left corner or
+    /// extent do not fit in the rectangle's field types.
+    pub fn from_edges(left: i64, top: i64, right: i64, bottom: i64) -> Option<Self> {
+        let x = i32::try_from(left).ok()?;
+        let y = i32::try_from(top).ok()?;
+        let width = u32::try_from(right.checked_sub(left)?).ok()?;
+        let height = u32::try_from(bottom.checked_sub(top)?).ok()?;
+        Some(Self::new(x, y, width, height))
+    }
+
+    pub const fn extent(self) -> Extent {
+        Extent::new(self.width, self.height)
+    }
+
+    pub const fn left(self) -> i64 {
+        self.x as i64
+    }
+
+    pub const fn top(self) -> i64 {
+        self.y as i64
+    }
+
+    /// The exclusive right edge, as `i64` since `x + width` can exceed `i32::MAX`.
+    pub const fn right(self) -> i64 {
+        self.x as i64 + self.width as i64
+    }
+
+    /// The exclusive bottom edge, as `i64` since `y + height` can exceed `i32::MAX`.
+    pub const fn bottom(self) -> i64 {
+        self.y as i64 + self.height as i64
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.extent().is_empty()
+    }
+
+    /// Whether the point lies inside the rectangle; the right and bottom edges are exclusive.
+    pub const fn contains(self, x: i32, y: i32) -> bool {
+        let (x, y) = (x as i64, y as i64);
+        x >= self.left() && x < self.right() && y >= self.top() && y < self.bottom()
+    }
+
+    /// The overlapping area of two rectangles, or `None` if they do not overlap.
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        let rect = Self::from_edges(
+            self.left().max(other.left()),
+            self.top().max(other.top()),
+            self.right().min(other.right()),
+            self.bottom().min(other.bottom()),
+        )?;
+        (!rect.is_empty()).then_some(rect)
+    }
+
+    /// The smallest rectangle containing both rectangles, or `None` if its extent does not
+    /// fit in `u32`.
+    pub fn union(self, other: Self) -> Option<Self> {
+        Self::from_edges(
+            self.left().min(other.left()),
+            self.top().min(other.top()),
+            self.right().max(other.right()),
+            self.bottom().max(other.bottom()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aspect_ratio_of_empty_extent_is_none() {
+        assert_eq!(
+            Extent::new(1920, 1080).aspect_ratio(),
+            Some(1920.0 / 1080.0)
+        );
+        assert_eq!(Extent::new(0, 1080).aspect_ratio(), None);
+    }
+
+    #[test]
+    fn contains_excludes_right_and_bottom_edges() {
+        let rect = Rect::new(0, 0, 10, 10);
+        assert!(rect.contains(0, 0));
+        assert!(rect.contains(9, 9));
+        assert!(!rect.contains(10, 0));
+        assert!(!rect.contains(0, 10));
+    }
+
+    #[test]
+    fn intersection_of_overlapping_rects() {
+        let a = Rect::new(0, 0, 10, 10);
+        let b = Rect::new(5, -5, 10, 10);
+        assert_eq!(a.intersection(b), Some(Rect::new(5, 0, 5, 5)));
+    }
+
+    #[test]
+    fn intersection_of_touching_or_disjoint_rects_is_none() {
+        let a = Rect::new(0, 0, 10, 10);
+        assert_eq!(a.intersection(Rect::new(10, 0, 10, 10)), None);
+        assert_eq!(a.intersection(Rect::new(0, 10, 10, 10)), None);
+        assert_eq!(a.intersection(Rect::new(20, 20, 1, 1)), None);
+    }
+
+    #[test]
+    fn union_covers_both_rects() {
+        let a = Rect::new(0, 0, 10, 10);
+        let b = Rect::new(-5, 5, 10, 10);
+        assert_eq!(a.union(b), Some(Rect::new(-5, 0, 15, 15)));
+    }
+
+    #[test]
+    fn edges_do_not_saturate_near_i32_max() {
+        let a = Rect::new(i32::MAX - 1, 0, 10, 10);
+        assert_eq!(a.right(), i32::MAX as i64 + 9);
+
+        let union = a.union(Rect::new(0, 0, 1, 1)).unwrap();
+        assert_eq!(union.width, i32::MAX as u32 + 9);
+    }
+
+    #[test]
+    fn union_too_wide_for_u32_is_none() {
+        let a = Rect::new(i32::MIN, 0, 1, 1);
+        let b = Rect::new(i32::MAX, 0, u32::MAX, 1);
+        assert_eq!(a.union(b), None);
+    }
+}